@@ -52,15 +52,42 @@
 //! assert_eq!(json, r#"{"a":42}"#);
 //! ```
 //!
+//! # Debugging
+//!
+//! Sometimes you need to see the real value during a controlled debugging session.
+//! [with_unredacted] (or the RAII guard [Unredacted]) disables redaction for the
+//! current thread only, so other threads keep redacting normally. Only wrapped
+//! `String`, `&'static str` and `Box<str>` values are revealed; any other wrapped type
+//! is still formatted by its [Redactor].
+//!
+//! ```rust
+//! # use redactrs::{Redacted, with_unredacted};
+//! let secret: Redacted<&str> = "sensitive".into();
+//!
+//! with_unredacted(|| {
+//!     assert_eq!(secret.to_string(), "sensitive");
+//! });
+//!
+//! assert_eq!(secret.to_string(), "<redacted>");
+//! ```
+//!
 //! # Feature flags
 //! - `serde`: Enables serde support.
+//! - `serde_with`: Enables the [serde_with] adapter for redacting fields whose type
+//!   you don't own, via [serde_with::RedactAs].
 //!
 
 pub mod redactors;
+mod unredacted;
 
 #[cfg(any(feature = "serde", doc))]
 pub mod serde;
 
+#[cfg(any(feature = "serde_with", doc))]
+pub mod serde_with;
+
+pub use crate::unredacted::{with_unredacted, Unredacted};
+
 use crate::redactors::Simple;
 #[cfg(doc)]
 use crate::redactors::*;
@@ -70,9 +97,16 @@ use std::fmt::{Debug, Display, Formatter, Result};
 use std::marker::PhantomData;
 
 /// A Trait to define how a value should be redacted.
-pub trait Redactor {
-    ///Function called by [Display] and [Debug].
-    fn redact(f: &mut Formatter<'_>) -> Result;
+pub trait Redactor<T> {
+    ///Function called by [Display]. Receives the wrapped value so the redaction can
+    ///depend on its content, e.g. its length or shape.
+    fn display_redacted(value: &T, f: &mut Formatter<'_>) -> Result;
+
+    ///Function called by [Debug]. Defaults to [Redactor::display_redacted], but can be
+    ///overridden to keep the inner type's debug "shape", e.g. quoting a redacted string.
+    fn debug_redacted(value: &T, f: &mut Formatter<'_>) -> Result {
+        Self::display_redacted(value, f)
+    }
 }
 
 /// Struct used to wrap sensitive content that should not be printed/logged.
@@ -94,7 +128,7 @@ pub trait Redactor {
 /// ```
 pub struct Redacted<T, R = Simple>
 where
-    R: Redactor,
+    R: Redactor<T>,
 {
     inner: T,
     _redactor: PhantomData<R>,
@@ -102,7 +136,7 @@ where
 
 impl<T, R> Redacted<T, R>
 where
-    R: Redactor,
+    R: Redactor<T>,
 {
     ///Consumes the [Redacted], returning the wrapped value.
     ///```rust
@@ -140,7 +174,7 @@ where
 impl<T, R> Default for Redacted<T, R>
 where
     T: Default,
-    R: Redactor,
+    R: Redactor<T>,
 {
     fn default() -> Self {
         Self {
@@ -152,7 +186,7 @@ where
 
 impl<T, R> From<T> for Redacted<T, R>
 where
-    R: Redactor,
+    R: Redactor<T>,
 {
     fn from(value: T) -> Self {
         Redacted {
@@ -165,7 +199,7 @@ where
 impl<T, R> Clone for Redacted<T, R>
 where
     T: Clone,
-    R: Redactor,
+    R: Redactor<T>,
 {
     fn clone(&self) -> Self {
         Redacted {
@@ -178,14 +212,14 @@ where
 impl<T, R> Copy for Redacted<T, R>
 where
     T: Copy,
-    R: Redactor,
+    R: Redactor<T>,
 {
 }
 
 impl<T, R> PartialEq for Redacted<T, R>
 where
     T: PartialEq,
-    R: Redactor,
+    R: Redactor<T>,
 {
     fn eq(&self, other: &Self) -> bool {
         self.inner.eq(&other.inner)
@@ -195,7 +229,7 @@ where
 impl<T, R> PartialEq<T> for Redacted<T, R>
 where
     T: PartialEq,
-    R: Redactor,
+    R: Redactor<T>,
 {
     fn eq(&self, other: &T) -> bool {
         self.inner.eq(other)
@@ -205,14 +239,14 @@ where
 impl<T, R> Eq for Redacted<T, R>
 where
     T: Eq,
-    R: Redactor,
+    R: Redactor<T>,
 {
 }
 
 impl<T, R> PartialOrd for Redacted<T, R>
 where
     T: PartialOrd,
-    R: Redactor,
+    R: Redactor<T>,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.inner.partial_cmp(&other.inner)
@@ -222,7 +256,7 @@ where
 impl<T, R> PartialOrd<T> for Redacted<T, R>
 where
     T: PartialOrd,
-    R: Redactor,
+    R: Redactor<T>,
 {
     fn partial_cmp(&self, other: &T) -> Option<Ordering> {
         self.inner().partial_cmp(other)
@@ -232,7 +266,7 @@ where
 impl<T, R> Ord for Redacted<T, R>
 where
     T: Ord,
-    R: Redactor,
+    R: Redactor<T>,
 {
     fn cmp(&self, other: &Self) -> Ordering {
         self.inner.cmp(&other.inner)
@@ -241,18 +275,30 @@ where
 
 impl<T, R> Display for Redacted<T, R>
 where
-    R: Redactor,
+    T: 'static,
+    R: Redactor<T>,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        R::redact(f)
+        if unredacted::is_unredacted() {
+            if let Some(result) = unredacted::try_reveal_display(&self.inner, f) {
+                return result;
+            }
+        }
+        R::display_redacted(&self.inner, f)
     }
 }
 
 impl<T, R> Debug for Redacted<T, R>
 where
-    R: Redactor,
+    T: 'static,
+    R: Redactor<T>,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        R::redact(f)
+        if unredacted::is_unredacted() {
+            if let Some(result) = unredacted::try_reveal_debug(&self.inner, f) {
+                return result;
+            }
+        }
+        R::debug_redacted(&self.inner, f)
     }
 }
@@ -1,6 +1,10 @@
 //! Contains ready to use [Redactor]s
 use crate::Redactor;
+use std::borrow::Cow;
 use std::fmt::Formatter;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::rc::Rc;
+use std::sync::Arc;
 
 /// [Redactor] that will redact the value to "\<redacted\>"
 /// ```rust
@@ -12,7 +16,7 @@ use std::fmt::Formatter;
 /// ```
 pub struct Simple;
 impl<T> Redactor<T> for Simple {
-    fn redact(_: &T, f: &mut Formatter) -> std::fmt::Result {
+    fn display_redacted(_: &T, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "<redacted>")
     }
 }
@@ -36,12 +40,49 @@ impl<T> Redactor<T> for Simple {
 /// ```
 pub struct Custom<const SYMBOL: char = '●', const REP: usize = 8>;
 impl<T, const SYMBOL: char, const REP: usize> Redactor<T> for Custom<SYMBOL, REP> {
-    fn redact(_: &T, f: &mut Formatter) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            std::iter::repeat(SYMBOL).take(REP).collect::<String>()
-        )
+    fn display_redacted(_: &T, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", std::iter::repeat_n(SYMBOL, REP).collect::<String>())
+    }
+}
+
+/// [Redactor] that reveals the first `PREFIX` and last `SUFFIX` characters and masks
+/// everything in between. Requires the inner type to impl [AsRef<str>].
+///
+/// ```rust
+/// # use redactrs::Redacted;
+/// # use redactrs::redactors::PartialReveal;
+/// let redacted_value: Redacted<_, PartialReveal<2, 2>> = "secret".into();
+///
+/// assert_eq!(redacted_value.to_string(), "se●●et");
+/// ```
+///
+/// If `PREFIX + SUFFIX` would reveal overlapping characters (i.e. is `>=` the value's
+/// length), the whole value is redacted instead, so short secrets are never exposed.
+///
+/// ```rust
+/// # use redactrs::Redacted;
+/// # use redactrs::redactors::PartialReveal;
+/// let redacted_value: Redacted<_, PartialReveal<2, 2>> = "abc".into();
+///
+/// assert_eq!(redacted_value.to_string(), "<redacted>");
+/// ```
+pub struct PartialReveal<const PREFIX: usize, const SUFFIX: usize>;
+impl<T: AsRef<str>, const PREFIX: usize, const SUFFIX: usize> Redactor<T>
+    for PartialReveal<PREFIX, SUFFIX>
+{
+    fn display_redacted(value: &T, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let value = value.as_ref();
+        let len = value.chars().count();
+
+        if PREFIX.saturating_add(SUFFIX) >= len {
+            return write!(f, "<redacted>");
+        }
+
+        let prefix: String = value.chars().take(PREFIX).collect();
+        let suffix: String = value.chars().skip(len - SUFFIX).collect();
+        let masked = "●".repeat(len - PREFIX - SUFFIX);
+
+        write!(f, "{prefix}{masked}{suffix}")
     }
 }
 
@@ -56,10 +97,110 @@ impl<T, const SYMBOL: char, const REP: usize> Redactor<T> for Custom<SYMBOL, REP
 /// ```
 pub struct ByteSize;
 impl<T: AsRef<[u8]>> Redactor<T> for ByteSize {
-    fn redact(value: &T, f: &mut Formatter<'_>) -> std::fmt::Result
+    fn display_redacted(value: &T, f: &mut Formatter<'_>) -> std::fmt::Result
     where
         Self: Sized,
     {
         write!(f, "<{} bytes redacted>", value.as_ref().len())
     }
 }
+
+/// [Redactor] for network addresses that reveals only coarse structure, following
+/// `safelog`'s style: enough to correlate logs (e.g. which /8 a connection came from)
+/// without leaking the full address. Ports are always dropped.
+///
+/// ```rust
+/// # use redactrs::Redacted;
+/// # use redactrs::redactors::Network;
+/// # use std::net::Ipv4Addr;
+/// let redacted_value: Redacted<_, Network> = Ipv4Addr::new(1, 2, 3, 4).into();
+///
+/// assert_eq!(redacted_value.to_string(), "1.x.x.x");
+/// ```
+///
+/// ```rust
+/// # use redactrs::Redacted;
+/// # use redactrs::redactors::Network;
+/// # use std::net::Ipv6Addr;
+/// let redacted_value: Redacted<_, Network> = "2001:db8::1".parse::<Ipv6Addr>().unwrap().into();
+///
+/// assert_eq!(redacted_value.to_string(), "2001:x:x:…");
+/// ```
+///
+/// ```rust
+/// # use redactrs::Redacted;
+/// # use redactrs::redactors::Network;
+/// # use std::net::SocketAddr;
+/// let redacted_value: Redacted<_, Network> = "1.2.3.4:8080".parse::<SocketAddr>().unwrap().into();
+///
+/// assert_eq!(redacted_value.to_string(), "1.x.x.x");
+/// ```
+pub struct Network;
+
+impl Redactor<Ipv4Addr> for Network {
+    fn display_redacted(value: &Ipv4Addr, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.x.x.x", value.octets()[0])
+    }
+}
+
+impl Redactor<Ipv6Addr> for Network {
+    fn display_redacted(value: &Ipv6Addr, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x}:x:x:…", value.segments()[0])
+    }
+}
+
+impl Redactor<SocketAddr> for Network {
+    fn display_redacted(value: &SocketAddr, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match value {
+            SocketAddr::V4(addr) => Network::display_redacted(addr.ip(), f),
+            SocketAddr::V6(addr) => Network::display_redacted(addr.ip(), f),
+        }
+    }
+}
+
+/// [Redactor] that behaves like [Simple] under [std::fmt::Display], but keeps the
+/// inner type's debug "shape" under `{:?}`: a redacted string is quoted like a real
+/// string would be, while other types print unquoted, matching Vector's
+/// `SensitiveString`.
+///
+/// Telling a string-like `T` apart from any other `T` at this point can't be done
+/// through a trait bound (that would make [TypeFaithful] stop applying to non-string
+/// types entirely), so this checks the concrete type at runtime via [std::any::Any]
+/// instead. This only recognizes `T`s commonly used for owned/borrowed strings
+/// (`String`, `&'static str`, `Box<str>`, `Cow<'static, str>`, `Rc<str>`, `Arc<str>`)
+/// and requires `T: 'static`, so e.g. a non-`'static` `&str` or a `Cow<'a, str>` with
+/// `'a != 'static` doesn't satisfy the `TypeFaithful` impl at all.
+///
+/// ```rust
+/// # use redactrs::Redacted;
+/// # use redactrs::redactors::TypeFaithful;
+/// let redacted_string: Redacted<_, TypeFaithful> = "secret".to_string().into();
+/// assert_eq!(format!("{:?}", redacted_string), "\"<redacted>\"");
+///
+/// let redacted_int: Redacted<_, TypeFaithful> = 42.into();
+/// assert_eq!(format!("{:?}", redacted_int), "<redacted>");
+/// ```
+pub struct TypeFaithful;
+impl<T: 'static> Redactor<T> for TypeFaithful {
+    fn display_redacted(_: &T, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<redacted>")
+    }
+
+    fn debug_redacted(value: &T, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use std::any::Any;
+
+        let value = value as &dyn Any;
+        let is_string_like = value.is::<String>()
+            || value.is::<&str>()
+            || value.is::<Box<str>>()
+            || value.is::<Cow<'static, str>>()
+            || value.is::<Rc<str>>()
+            || value.is::<Arc<str>>();
+
+        if is_string_like {
+            write!(f, "{:?}", "<redacted>")
+        } else {
+            write!(f, "<redacted>")
+        }
+    }
+}
@@ -9,8 +9,8 @@ use crate::{Redacted, Redactor};
 /// Requires feature `serde`.
 impl<T, R> Serialize for Redacted<T, R>
 where
-    T: Serialize,
-    R: Redactor,
+    T: Serialize + 'static,
+    R: Redactor<T>,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -25,7 +25,7 @@ where
 impl<'de, T, R> Deserialize<'de> for Redacted<T, R>
 where
     T: Deserialize<'de>,
-    R: Redactor,
+    R: Redactor<T>,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -56,7 +56,7 @@ pub fn no_redact<T, R, S>(value: &Redacted<T, R>, serializer: S) -> Result<S::Ok
 where
     S: Serializer,
     T: Serialize,
-    R: Redactor,
+    R: Redactor<T>,
 {
     value.inner.serialize(serializer)
 }
@@ -0,0 +1,65 @@
+//! Adapter for the [`serde_with`](https://docs.rs/serde_with) crate, for redacting
+//! fields whose type you don't own and therefore can't wrap in [Redacted](crate::Redacted).
+//!
+//! Requires feature `serde_with`.
+
+use crate::Redactor;
+use serde::{Deserialize, Deserializer, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+
+/// [SerializeAs]/[DeserializeAs] marker that redacts a field with `R` on serialize,
+/// and passes the value through unchanged on deserialize.
+///
+/// ```rust,ignore
+/// use redactrs::redactors::Simple;
+/// use redactrs::serde_with::RedactAs;
+/// use serde_with::serde_as;
+///
+/// #[serde_as]
+/// #[derive(serde::Serialize)]
+/// struct MyData {
+///     #[serde_as(as = "RedactAs<Simple>")]
+///     api_key: String,
+/// }
+/// ```
+///
+/// Like other `serde_with` adapters, this composes with containers, e.g.
+/// `#[serde_as(as = "Vec<RedactAs<Simple>>")]` or `#[serde_as(as = "Option<RedactAs<Simple>>")]`.
+///
+/// Pairs with [no_redact](crate::serde::no_redact) for the inverse direction.
+///
+/// Requires feature `serde_with`.
+pub struct RedactAs<R>(PhantomData<R>);
+
+impl<T, R> SerializeAs<T> for RedactAs<R>
+where
+    R: Redactor<T>,
+{
+    fn serialize_as<S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        struct Redact<'a, T, R>(&'a T, PhantomData<R>);
+        impl<T, R: Redactor<T>> Display for Redact<'_, T, R> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                R::display_redacted(self.0, f)
+            }
+        }
+
+        serializer.serialize_str(&Redact::<T, R>(value, PhantomData).to_string())
+    }
+}
+
+impl<'de, T, R> DeserializeAs<'de, T> for RedactAs<R>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer)
+    }
+}
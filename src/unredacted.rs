@@ -0,0 +1,119 @@
+//! Runtime switch to temporarily disable redaction for debugging.
+//!
+//! This mirrors the approach taken by Tor's `safelog` crate: a thread-local flag
+//! that [Display]/[Debug] check before handing off to the [Redactor](crate::Redactor),
+//! so operators can inspect real values during a controlled debugging session without
+//! changing any code that constructs a [Redacted](crate::Redacted).
+//!
+//! There is no `T: Display`/`T: Debug` bound on [Redacted](crate::Redacted) itself (that
+//! would make it stop applying to types like `Vec<u8>`, which [redactors::ByteSize](crate::redactors::ByteSize)
+//! exists to redact), so revealing the real value can't go through `T`'s own `Display`/`Debug`
+//! impl directly. Instead, [try_reveal_display]/[try_reveal_debug] recognize a fixed list of
+//! concrete string-like types via [std::any::Any] and show those; everything else is still
+//! formatted by its [Redactor](crate::Redactor) while unredacted, the same as it would otherwise.
+//!
+//! The flag is thread-local on purpose: unredacting in one thread must never leak
+//! another thread's data.
+
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt::{Debug, Display, Formatter, Result};
+
+thread_local! {
+    static UNREDACTED: Cell<bool> = const { Cell::new(false) };
+}
+
+pub(crate) fn is_unredacted() -> bool {
+    UNREDACTED.with(|flag| flag.get())
+}
+
+/// Formats `value` via [Display] if it's one of a fixed list of concrete string-like
+/// types, returning `None` for everything else so the caller can fall back to the
+/// [Redactor](crate::Redactor).
+pub(crate) fn try_reveal_display<T: 'static>(value: &T, f: &mut Formatter<'_>) -> Option<Result> {
+    let value = value as &dyn Any;
+    if let Some(s) = value.downcast_ref::<String>() {
+        Some(Display::fmt(s, f))
+    } else if let Some(s) = value.downcast_ref::<&str>() {
+        Some(Display::fmt(s, f))
+    } else if let Some(s) = value.downcast_ref::<Box<str>>() {
+        Some(Display::fmt(s, f))
+    } else {
+        None
+    }
+}
+
+/// [Debug] counterpart of [try_reveal_display], quoting the revealed string like
+/// [Debug] normally would.
+pub(crate) fn try_reveal_debug<T: 'static>(value: &T, f: &mut Formatter<'_>) -> Option<Result> {
+    let value = value as &dyn Any;
+    if let Some(s) = value.downcast_ref::<String>() {
+        Some(Debug::fmt(s, f))
+    } else if let Some(s) = value.downcast_ref::<&str>() {
+        Some(Debug::fmt(s, f))
+    } else if let Some(s) = value.downcast_ref::<Box<str>>() {
+        Some(Debug::fmt(s, f))
+    } else {
+        None
+    }
+}
+
+/// Runs `f` with unredaction enabled for the current thread.
+///
+/// While `f` runs, [Display]/[Debug] on [Redacted](crate::Redacted) values formatted on
+/// this thread print the wrapped value itself instead of going through the [Redactor](crate::Redactor).
+/// Only wrapped `String`, `&'static str` and `Box<str>` values are revealed this way; any
+/// other wrapped type is still formatted by its [Redactor](crate::Redactor).
+/// The previous state is restored once `f` returns, so nested calls behave correctly.
+///
+/// ```rust
+/// # use redactrs::{Redacted, with_unredacted};
+/// let secret: Redacted<&str> = "sensitive".into();
+///
+/// with_unredacted(|| {
+///     assert_eq!(secret.to_string(), "sensitive");
+/// });
+///
+/// assert_eq!(secret.to_string(), "<redacted>");
+/// ```
+pub fn with_unredacted<F, Out>(f: F) -> Out
+where
+    F: FnOnce() -> Out,
+{
+    let _guard = Unredacted::enable();
+    f()
+}
+
+/// RAII guard, returned by [Unredacted::enable], that disables redaction for as long
+/// as it stays alive. As with [with_unredacted], only wrapped `String`, `&'static str`
+/// and `Box<str>` values are revealed; other types are still redacted.
+///
+/// Dropping the guard restores whatever state was active before it was created
+/// (not unconditionally "redacted"), so guards nest correctly.
+///
+/// ```rust
+/// # use redactrs::{Redacted, Unredacted};
+/// let secret: Redacted<&str> = "sensitive".into();
+/// {
+///     let _guard = Unredacted::enable();
+///     assert_eq!(secret.to_string(), "sensitive");
+/// }
+/// assert_eq!(secret.to_string(), "<redacted>");
+/// ```
+pub struct Unredacted {
+    previous: bool,
+}
+
+impl Unredacted {
+    /// Enables unredaction for the current thread until the returned guard is dropped.
+    pub fn enable() -> Self {
+        let previous = UNREDACTED.with(|flag| flag.replace(true));
+        Unredacted { previous }
+    }
+}
+
+impl Drop for Unredacted {
+    fn drop(&mut self) {
+        UNREDACTED.with(|flag| flag.set(self.previous));
+    }
+}
@@ -12,7 +12,7 @@ use zeroize::{TryZeroize, Zeroize, ZeroizeOnDrop};
 impl<T, R> Zeroize for Redacted<T, R>
 where
     T: Zeroize,
-    R: Redactor,
+    R: Redactor<T>,
 {
     fn zeroize(&mut self) {
         self.inner.zeroize()
@@ -23,7 +23,7 @@ where
 impl<T, R> TryZeroize for Redacted<T, R>
 where
     T: TryZeroize,
-    R: Redactor,
+    R: Redactor<T>,
 {
     fn try_zeroize(&mut self) -> bool {
         self.inner.try_zeroize()
@@ -34,6 +34,6 @@ where
 impl<T, R> ZeroizeOnDrop for Redacted<T, R>
 where
     T: ZeroizeOnDrop,
-    R: Redactor,
+    R: Redactor<T>,
 {
 }
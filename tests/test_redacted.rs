@@ -1,6 +1,8 @@
-use redactrs::redactors::{Custom, Simple};
-use redactrs::Redacted;
+use redactrs::redactors::{ByteSize, Custom, Network, PartialReveal, Simple, TypeFaithful};
+use redactrs::{Redacted, Unredacted};
 use serde::Serialize;
+use std::borrow::Cow;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
 use zeroize::Zeroize;
 
 #[test]
@@ -156,3 +158,133 @@ fn zeroize() {
 
     assert_eq!(*x.inner(), 0);
 }
+
+#[test]
+fn partial_reveal() {
+    let x: Redacted<_, PartialReveal<2, 2>> = "secret".into();
+    assert_eq!(x.to_string(), "se●●et");
+}
+
+#[test]
+fn partial_reveal_too_short_redacts_fully() {
+    let x: Redacted<_, PartialReveal<2, 2>> = "abc".into();
+    assert_eq!(x.to_string(), "<redacted>");
+}
+
+#[test]
+fn partial_reveal_counts_chars_not_bytes() {
+    let x: Redacted<_, PartialReveal<1, 1>> = "héllo".into();
+    assert_eq!(x.to_string(), "h●●●o");
+}
+
+#[test]
+fn byte_size_formats_and_serializes_non_display_inner_type() {
+    // Vec<u8> has no Display impl - Redacted<T, R> must stay usable for it.
+    let x: Redacted<_, ByteSize> = vec![1u8, 2, 3].into();
+    assert_eq!(x.to_string(), "<3 bytes redacted>");
+    assert_eq!(format!("{:?}", x), "<3 bytes redacted>");
+
+    let json = serde_json::to_string(&x).expect("Test case");
+    assert_eq!(json, r#""<3 bytes redacted>""#);
+}
+
+#[test]
+fn network_ipv4() {
+    let x: Redacted<_, Network> = Ipv4Addr::new(203, 0, 113, 42).into();
+    assert_eq!(x.to_string(), "203.x.x.x");
+}
+
+#[test]
+fn network_ipv6() {
+    let x: Redacted<_, Network> = "2001:db8::1".parse::<Ipv6Addr>().unwrap().into();
+    assert_eq!(x.to_string(), "2001:x:x:…");
+}
+
+#[test]
+fn network_socket_addr_drops_port() {
+    let x: Redacted<_, Network> = "203.0.113.42:8080".parse::<SocketAddr>().unwrap().into();
+    assert_eq!(x.to_string(), "203.x.x.x");
+}
+
+#[test]
+fn redact_as_serializes_redacted_and_deserializes_unchanged() {
+    use redactrs::redactors::Simple;
+    use redactrs::serde_with::RedactAs;
+    use serde_with::serde_as;
+
+    #[serde_as]
+    #[derive(Serialize, serde::Deserialize)]
+    struct MyData {
+        #[serde_as(as = "RedactAs<Simple>")]
+        api_key: String,
+        id: i32,
+    }
+
+    let data = MyData {
+        api_key: "sk-secret".to_string(),
+        id: 42,
+    };
+
+    let json = serde_json::to_string(&data).expect("Test case");
+    assert_eq!(json, r#"{"api_key":"<redacted>","id":42}"#);
+
+    let round_tripped: MyData =
+        serde_json::from_str(r#"{"api_key":"sk-secret","id":42}"#).expect("Test case");
+    assert_eq!(round_tripped.api_key, "sk-secret");
+}
+
+#[test]
+fn type_faithful_quotes_strings_in_debug() {
+    let x: Redacted<_, TypeFaithful> = "secret".to_string().into();
+    assert_eq!(x.to_string(), "<redacted>");
+    assert_eq!(format!("{:?}", x), "\"<redacted>\"");
+}
+
+#[test]
+fn type_faithful_does_not_quote_non_strings_in_debug() {
+    let x: Redacted<_, TypeFaithful> = 42.into();
+    assert_eq!(format!("{:?}", x), "<redacted>");
+}
+
+#[test]
+fn type_faithful_quotes_other_owned_string_types_in_debug() {
+    let cow: Redacted<_, TypeFaithful> = Cow::<'static, str>::Borrowed("secret").into();
+    assert_eq!(format!("{:?}", cow), "\"<redacted>\"");
+
+    let rc: Redacted<_, TypeFaithful> = std::rc::Rc::<str>::from("secret").into();
+    assert_eq!(format!("{:?}", rc), "\"<redacted>\"");
+
+    let arc: Redacted<_, TypeFaithful> = std::sync::Arc::<str>::from("secret").into();
+    assert_eq!(format!("{:?}", arc), "\"<redacted>\"");
+}
+
+#[test]
+fn with_unredacted_shows_real_value() {
+    use redactrs::with_unredacted;
+
+    let secret: Redacted<&str, Simple> = "sensitive".into();
+
+    with_unredacted(|| {
+        assert_eq!(secret.to_string(), "sensitive");
+        assert_eq!(format!("{:?}", secret), "\"sensitive\"");
+    });
+
+    assert_eq!(secret.to_string(), "<redacted>");
+}
+
+#[test]
+fn unredacted_guard_restores_previous_state_on_drop() {
+    let secret: Redacted<&str, Simple> = "sensitive".into();
+
+    {
+        let _outer = Unredacted::enable();
+        assert_eq!(secret.to_string(), "sensitive");
+        {
+            let _inner = Unredacted::enable();
+            assert_eq!(secret.to_string(), "sensitive");
+        }
+        assert_eq!(secret.to_string(), "sensitive");
+    }
+
+    assert_eq!(secret.to_string(), "<redacted>");
+}